@@ -1,4 +1,5 @@
-use std::{error::Error, fmt::Debug, fmt::Display};
+use regex::Regex;
+use std::{cmp::Ordering, error::Error, fmt::Debug, fmt::Display};
 
 /*
     Parsing is a process of deriving structure from a stream of data.
@@ -21,6 +22,7 @@ use std::{error::Error, fmt::Debug, fmt::Display};
     <na-2me-></na-2me->
 */
 
+#[derive(Debug, PartialEq)]
 struct Element {
     name: String,
     attributes: Vec<(String, String)>,
@@ -28,22 +30,83 @@ struct Element {
 }
 
 
+/*
+    a bare &str error only tells you the leftover input, not why parsing
+    stopped there. `remaining` keeps that, `position` is the byte offset
+    into whatever input the failing parser started from, and `expected`
+    collects what would have made it succeed (see `label`)
+*/
+#[derive(Debug, Clone, PartialEq)]
+struct ParseError<'a> {
+    remaining: &'a str,
+    position: usize,
+    expected: Vec<String>,
+}
+
+impl<'a> ParseError<'a> {
+    // `input` is the slice the failing parser started from, `remaining` is
+    // what was left when it gave up
+    fn new(input: &'a str, remaining: &'a str, expected: &str) -> Self {
+        ParseError {
+            remaining,
+            position: input.len() - remaining.len(),
+            expected: vec![expected.to_string()],
+        }
+    }
+}
+
 // lifetime 'a refers specifically to the lifetime of the input
-type ParserOutput<'a, Output> = Result<(&'a str, Output), &'a str>;
+type ParserOutput<'a, Output> = Result<(&'a str, Output), ParseError<'a>>;
 
 trait Parser<'a, Output> {
     fn parse(&self, input: &'a str) -> ParserOutput<'a, Output>;
+
+    /*
+        `impl Parser` can't be returned from a trait method (not object-safe),
+        so these default methods box the result up as a BoxedParser instead.
+        lets callers write identifier.map(...).pred(...) instead of nesting
+        map(pred(identifier, ...), ...) inside-out.
+    */
+    fn map<F, NewOutput>(self, map_fn: F) -> BoxedParser<'a, NewOutput>
+    where
+        Self: Sized + 'a,
+        Output: 'a,
+        NewOutput: 'a,
+        F: Fn(Output) -> NewOutput + 'a,
+    {
+        BoxedParser::new(map(self, map_fn))
+    }
+
+    fn pred<F>(self, pred_fn: F) -> BoxedParser<'a, Output>
+    where
+        Self: Sized + 'a,
+        Output: 'a,
+        F: Fn(&Output) -> bool + 'a,
+    {
+        BoxedParser::new(pred(self, pred_fn))
+    }
+
+    fn and_then<F, NextParser, NewOutput>(self, f: F) -> BoxedParser<'a, NewOutput>
+    where
+        Self: Sized + 'a,
+        Output: 'a,
+        NewOutput: 'a,
+        NextParser: Parser<'a, NewOutput> + 'a,
+        F: Fn(Output) -> NextParser + 'a,
+    {
+        BoxedParser::new(and_then(self, f))
+    }
 }
 
 /*
     implementing Parser trait for any function that matches it's signature
-    (any function that takes a string slice and returns a ParseResult is considered a Parser), 
+    (any function that takes a string slice and returns a ParseResult is considered a Parser),
     but eventually it doesn't mean there won't be implementations for another structs or types
 
     This way we also open up the possibility to use other kinds of types as parsers.
     But, more importantly, it saves us from having to type out function signatures all the time.
 */
-impl <'a, F, Output> Parser<'a, Output> for F 
+impl <'a, F, Output> Parser<'a, Output> for F
 where
     F: Fn(&'a str) -> ParserOutput<Output>,
 {
@@ -52,6 +115,32 @@ where
     }
 }
 
+/*
+    a `Box<dyn Parser>` wearing a Parser impl of its own, so trait methods
+    that need to return "some parser" can return one concrete type
+    regardless of what combinator produced it
+*/
+struct BoxedParser<'a, Output> {
+    parser: Box<dyn Parser<'a, Output> + 'a>,
+}
+
+impl<'a, Output> BoxedParser<'a, Output> {
+    fn new<P>(parser: P) -> Self
+    where
+        P: Parser<'a, Output> + 'a,
+    {
+        BoxedParser {
+            parser: Box::new(parser),
+        }
+    }
+}
+
+impl<'a, Output> Parser<'a, Output> for BoxedParser<'a, Output> {
+    fn parse(&self, input: &'a str) -> ParserOutput<'a, Output> {
+        self.parser.parse(input)
+    }
+}
+
 // fn identity_combinator<'a, P, I, O>(parser: P, identity: I) -> impl Fn(&'a str) -> ParserOutput<O>
 // where
 //     P: Parser<'a, O>,
@@ -94,6 +183,130 @@ where
     map(pair(parser1, parser2), |(left, _right)| left)
 }
 
+// left's mirror image: keep parser2's result, discard parser1's
+fn right<'a, P1, P2, R1, R2>(parser1: P1, parser2: P2) -> impl Parser<'a, R2>
+where
+    P1: Parser<'a, R1>,
+    P2: Parser<'a, R2>,
+{
+    map(pair(parser1, parser2), |(_left, right)| right)
+}
+
+// combines two alternatives' failures: if one got further into the input
+// than the other, it's the more specific failure, so keep it; if they
+// failed at the same position, neither is more specific, so union their
+// `expected` sets instead of silently dropping one
+fn merge_errors<'a>(err1: ParseError<'a>, err2: ParseError<'a>) -> ParseError<'a> {
+    match err1.position.cmp(&err2.position) {
+        Ordering::Greater => err1,
+        Ordering::Less => err2,
+        Ordering::Equal => {
+            let mut expected = err1.expected;
+            for exp in err2.expected {
+                if !expected.contains(&exp) {
+                    expected.push(exp);
+                }
+            }
+            ParseError { expected, ..err1 }
+        }
+    }
+}
+
+/*
+    try parser1; if it fails, try parser2 instead. parsers here don't
+    backtrack on their own once they've consumed input, so the invariant
+    that makes this safe is: parser2 always runs against the original,
+    untouched `input` - never wherever parser1 happened to give up - so
+    parser1 partially consuming input before failing can't corrupt the
+    second attempt. if both fail, their errors are merged (see merge_errors)
+*/
+fn either<'a, P1, P2, A>(parser1: P1, parser2: P2) -> impl Parser<'a, A>
+where
+    P1: Parser<'a, A>,
+    P2: Parser<'a, A>,
+{
+    move |input| match parser1.parse(input) {
+        ok @ Ok(_) => ok,
+        Err(err1) => match parser2.parse(input) {
+            ok @ Ok(_) => ok,
+            Err(err2) => Err(merge_errors(err1, err2)),
+        },
+    }
+}
+
+/*
+    n-ary either: tries each parser in turn against the original input,
+    returning the first success
+*/
+fn choice<'a, A>(parsers: Vec<BoxedParser<'a, A>>) -> impl Parser<'a, A> {
+    move |input| {
+        let mut furthest: Option<ParseError<'a>> = None;
+
+        for parser in parsers.iter() {
+            match parser.parse(input) {
+                ok @ Ok(_) => return ok,
+                Err(err) => {
+                    furthest = Some(match furthest {
+                        Some(prev) => merge_errors(prev, err),
+                        None => err,
+                    });
+                }
+            }
+        }
+
+        Err(furthest.expect("choice called with no parsers"))
+    }
+}
+
+/*
+    applies parser as many times as it succeeds (zero or more), collecting
+    every produced value into a Vec. never fails on its own, since zero
+    matches is a valid result - the backbone for attribute lists / children
+*/
+fn zero_or_more<'a, P, A>(parser: P) -> impl Parser<'a, Vec<A>>
+where
+    P: Parser<'a, A>,
+{
+    move |mut input| {
+        let mut results = Vec::new();
+
+        while let Ok((next_input, next_item)) = parser.parse(input) {
+            input = next_input;
+            results.push(next_item);
+        }
+
+        Ok((input, results))
+    }
+}
+
+/*
+    same as zero_or_more, but requires the very first application to succeed,
+    so the result always has at least one element
+*/
+fn one_or_more<'a, P, A>(parser: P) -> impl Parser<'a, Vec<A>>
+where
+    P: Parser<'a, A>,
+{
+    move |mut input| {
+        let mut results = Vec::new();
+
+        match parser.parse(input) {
+            Ok((next_input, first_item)) => {
+                input = next_input;
+                results.push(first_item);
+            }
+            Err(err) => return Err(err),
+        }
+
+        while let Ok((next_input, next_item)) = parser.parse(input) {
+            input = next_input;
+            results.push(next_item);
+        }
+
+        Ok((input, results))
+    }
+}
+
 
 fn main() -> Result<(), Box<dyn Error>> {
     // let mapper = map(take_first_char, |input| input);
@@ -120,13 +333,62 @@ fn take_first_char(input: &str) -> ParserOutput<char> {
     let first_char = input.chars().next();
 
     match first_char {
-        // utf8 char could take from 1 to 4 bytes 
+        // utf8 char could take from 1 to 4 bytes
         // and string slice operates bytes at [..] operation
         Some(c) => Ok((&input[c.len_utf8()..], c)),
-        _ => Err(input),
+        _ => Err(ParseError::new(input, input, "any character")),
     }
 }
 
+/*
+    matches any single character, succeeding everywhere take_first_char does -
+    exists as a named parser so it can be combined with pred (e.g. to build
+    whitespace_char or the "any char but a quote" parser used by quoted_string)
+*/
+fn any_char(input: &str) -> ParserOutput<char> {
+    take_first_char(input)
+}
+
+/*
+    a single whitespace character, built from any_char + pred
+*/
+fn whitespace_char<'a>() -> impl Parser<'a, char> {
+    pred(any_char, |c: &char| c.is_whitespace())
+}
+
+/*
+    one or more whitespace characters - used to separate attributes from
+    the tag name and from each other
+*/
+fn space1<'a>() -> impl Parser<'a, Vec<char>> {
+    one_or_more(whitespace_char())
+}
+
+/*
+    zero or more whitespace characters, for the places where whitespace is
+    allowed but not required
+*/
+fn space0<'a>() -> impl Parser<'a, Vec<char>> {
+    zero_or_more(whitespace_char())
+}
+
+/*
+    a "..." string, e.g. an attribute value. doesn't handle escaping -
+    just everything up to the closing quote
+*/
+fn quoted_string<'a>() -> impl Parser<'a, String> {
+    map(
+        pair(
+            match_literal("\""),
+            left(
+                zero_or_more(pred(any_char, |c| *c != '"')),
+                match_literal("\""),
+            ),
+        ),
+        |(_, chars)| chars.into_iter().collect(),
+    )
+}
+
 /*
     check whether a given input string begins with a specific match_literal string,
     but no need to return this literal as a part of a result. will be used for example
@@ -142,7 +404,7 @@ fn match_literal(expected: &'static str) -> impl Fn(&str) -> ParserOutput<()> {
 
         match expected_input_slice {
             Some(next) if next == expected => Ok((&input[expected.len()..], ())),
-            _ => Err(input),
+            _ => Err(ParseError::new(input, input, expected)),
         }
     }
 }
@@ -160,7 +422,7 @@ fn identifier(input: &str) -> ParserOutput<String> {
     // identifier has to start from alphabetic char only
     match chars.next() {
         Some(next) if next.is_alphabetic() => matched.push(next),
-        _ => return Err(input),
+        _ => return Err(ParseError::new(input, input, "an identifier")),
     }
 
     while let Some(next) = chars.next() {
@@ -176,6 +438,133 @@ fn identifier(input: &str) -> ParserOutput<String> {
     Ok((&input[next_index..], matched))
 }
 
+/*
+    matches `re` anchored at the start of the input, for token classes that
+    would otherwise need their own hand-rolled scanning loop (number
+    literals, CDATA-like content, custom identifier dialects)
+*/
+fn match_regex<'a>(re: &'a str) -> impl Parser<'a, String> {
+    let regex = Regex::new(&format!("^(?:{})", re)).expect("invalid regex");
+
+    move |input: &'a str| match regex.find(input) {
+        Some(found) => Ok((&input[found.end()..], found.as_str().to_string())),
+        None => Err(ParseError::new(input, input, re)),
+    }
+}
+
+/* -- -- -- -- -- -- -- -- Element Parser -- -- -- -- -- -- -- -- -- */
+
+/*
+    a single name="value" pair, e.g. one-half of what's between
+    <name and the closing /> or >
+*/
+fn attribute_pair<'a>() -> impl Parser<'a, (String, String)> {
+    pair(
+        identifier,
+        map(pair(match_literal("="), quoted_string()), |(_, value)| value),
+    )
+}
+
+/*
+    zero or more space-separated attribute pairs, each preceded by the
+    whitespace that separates it from the tag name / the previous attribute
+*/
+fn attributes<'a>() -> impl Parser<'a, Vec<(String, String)>> {
+    zero_or_more(map(pair(space1(), attribute_pair()), |(_, attr)| attr))
+}
+
+/*
+    everything from the opening < up to (but not including) the closing
+    "/>" or ">" - the tag name plus its attributes. trailing whitespace
+    before the terminator (e.g. the space in <img src="cat.png" />) is
+    consumed here so callers can match the terminator literal directly
+*/
+fn element_start<'a>() -> impl Parser<'a, (String, Vec<(String, String)>)> {
+    map(
+        pair(
+            pair(match_literal("<"), label(identifier, "tag name")),
+            left(attributes(), space0()),
+        ),
+        |((_, name), attributes)| (name, attributes),
+    )
+}
+
+/*
+    a self-closing tag, e.g. <br/> or <img src="cat.png"/>
+*/
+fn single_element<'a>() -> impl Parser<'a, Element> {
+    map(left(element_start(), match_literal("/>")), |(name, attributes)| {
+        Element {
+            name,
+            attributes,
+            children: Vec::new(),
+        }
+    })
+}
+
+/*
+    the </name> closing a parent element - rejects anything that doesn't
+    close the specific name the opening tag parsed. bespoke rather than
+    .pred()-based so the error names the tag that was actually expected,
+    instead of falling back to pred's generic "value matching predicate"
+*/
+fn close_element<'a>(expected_name: String) -> impl Parser<'a, String> {
+    move |input| {
+        let expected_closing_tag = format!("closing tag </{}>", expected_name);
+
+        match right(match_literal("</"), left(identifier, match_literal(">"))).parse(input) {
+            Ok((next_input, name)) if name == expected_name => Ok((next_input, name)),
+            Ok(_) => Err(ParseError::new(input, input, &expected_closing_tag)),
+            Err(err) => Err(ParseError {
+                expected: vec![expected_closing_tag],
+                ..err
+            }),
+        }
+    }
+}
+
+/*
+    an element with children, e.g. <parent><child/></parent>. and_then lets
+    the closing-tag check depend on the name element_start just parsed.
+    zero_or_more(element) recurses into element for every child, so element
+    stays a plain fn to give the type-checker a concrete, nameable type to
+    recurse through
+*/
+fn parent_element<'a>() -> impl Parser<'a, Element> {
+    element_start().and_then(|(name, attributes)| {
+        right(
+            match_literal(">"),
+            left(zero_or_more(element), close_element(name.clone())),
+        )
+        .map(move |children| Element {
+            name: name.clone(),
+            attributes: attributes.clone(),
+            children,
+        })
+    })
+}
+
+/*
+    trims leading and trailing whitespace around whatever parser is passed in -
+    elements are allowed to have whitespace/indentation around them
+*/
+fn whitespace_wrap<'a, P, A>(parser: P) -> impl Parser<'a, A>
+where
+    P: Parser<'a, A>,
+{
+    map(pair(space0(), left(parser, space0())), |(_, result)| result)
+}
+
+/*
+    the entry point of the whole parser: a single_element or a parent_element,
+    wrapped in whitespace_wrap. unlike parent_element this has to be a plain
+    parsing fn rather than one returning impl Parser, since parent_element
+    recurses back into this one for its children
+*/
+fn element(input: &str) -> ParserOutput<Element> {
+    whitespace_wrap(either(single_element(), parent_element())).parse(input)
+}
+
 /* -- -- -- -- -- -- -- -- Combinators -- -- -- -- -- -- -- -- -- */
 
 fn pair<'a, P1, P2, R1, R2>(
@@ -189,7 +578,13 @@ where
     move |input| match parser1.parse(input) {
         Ok((next_input, result1)) => match parser2.parse(next_input) {
             Ok((final_input, result2)) => Ok((final_input, (result1, result2))),
-            Err(err) => Err(err),
+            // parser2's error reports its position relative to next_input;
+            // rebase it onto pair's own input so the position reflects how
+            // far into *this* call parsing got
+            Err(mut err) => {
+                err.position = input.len() - err.remaining.len();
+                Err(err)
+            }
         },
         Err(err) => Err(err),
     }
@@ -233,6 +628,66 @@ where
     }
 }
 
+/*
+    runs parser, then only keeps the success if pred_fn accepts the produced
+    value. on rejection returns Err with the *original* input, not whatever
+    the inner parser consumed, so the failed attempt doesn't leak
+*/
+fn pred<'a, P, A, F>(parser: P, pred_fn: F) -> impl Parser<'a, A>
+where
+    P: Parser<'a, A>,
+    F: Fn(&A) -> bool,
+{
+    move |input| {
+        if let Ok((next_input, value)) = parser.parse(input) {
+            if pred_fn(&value) {
+                return Ok((next_input, value));
+            }
+        }
+
+        Err(ParseError::new(input, input, "value matching predicate"))
+    }
+}
+
+/*
+    overrides whatever expectation a failing parser reports with a single
+    human-readable one - label(identifier, "tag name") is a lot more useful
+    to read than "an identifier" when identifier is standing in for a tag name
+*/
+fn label<'a, P, A>(parser: P, message: &'static str) -> impl Parser<'a, A>
+where
+    P: Parser<'a, A>,
+{
+    move |input| {
+        parser.parse(input).map_err(|err| ParseError {
+            expected: vec![message.to_string()],
+            ..err
+        })
+    }
+}
+
+/*
+    runs parser, feeds its output into f to get a *second* parser, then runs
+    that one on the remaining input. lets later parsing depend on a value
+    produced earlier - e.g. matching a closing tag against the name the
+    opening tag parsed
+*/
+fn and_then<'a, P, F, A, B, NextP>(parser: P, f: F) -> impl Parser<'a, B>
+where
+    P: Parser<'a, A>,
+    NextP: Parser<'a, B>,
+    F: Fn(A) -> NextP,
+{
+    move |input| match parser.parse(input) {
+        // rebase the second parser's error onto and_then's own input, as pair does
+        Ok((next_input, result)) => f(result).parse(next_input).map_err(|mut err| {
+            err.position = input.len() - err.remaining.len();
+            err
+        }),
+        Err(err) => Err(err),
+    }
+}
+
 // fn identifier_with_rule<R>(input: &str, verifier: R) -> ParserOutput<String> 
 // where 
 //     R: Fn(char) -> bool    
@@ -267,13 +722,26 @@ fn test_match_literal() {
 
     assert_eq!(Ok(("", ())), parse_opening_angle_bracket("<"));
     assert_eq!(Ok(("name>", ())), parse_opening_angle_bracket("<name>"));
-    assert_eq!(Err("foo"), parse_opening_angle_bracket("foo"));
+    assert_eq!(
+        Err(ParseError::new("foo", "foo", "<")),
+        parse_opening_angle_bracket("foo")
+    );
 }
 
 #[test]
 fn test_identifier() {
-    assert_eq!(Err(""), identifier(""));
-    assert_eq!(Err("!not-identifier"), identifier("!not-identifier"));
+    assert_eq!(
+        Err(ParseError::new("", "", "an identifier")),
+        identifier("")
+    );
+    assert_eq!(
+        Err(ParseError::new(
+            "!not-identifier",
+            "!not-identifier",
+            "an identifier"
+        )),
+        identifier("!not-identifier")
+    );
     assert_eq!(
         Ok(("", String::from("is-identifier"))),
         identifier("is-identifier")
@@ -281,6 +749,124 @@ fn test_identifier() {
     assert_eq!(Ok(("😎", String::from("name-"))), identifier("name-😎"));
 }
 
+#[test]
+fn test_zero_or_more() {
+    let parser = zero_or_more(match_literal("ha"));
+
+    assert_eq!(Ok(("", vec![(), (), ()])), parser.parse("hahaha"));
+    assert_eq!(Ok(("ahah", vec![])), parser.parse("ahah"));
+    assert_eq!(Ok(("", vec![])), parser.parse(""));
+}
+
+#[test]
+fn test_one_or_more() {
+    let parser = one_or_more(match_literal("ha"));
+
+    assert_eq!(Ok(("", vec![(), (), ()])), parser.parse("hahaha"));
+    assert_eq!(
+        Err(ParseError::new("ahah", "ahah", "ha")),
+        parser.parse("ahah")
+    );
+    assert_eq!(Err(ParseError::new("", "", "ha")), parser.parse(""));
+}
+
+#[test]
+fn test_pred() {
+    let parser = pred(any_char, |c| *c == 'o');
+
+    assert_eq!(Ok(("mg", 'o')), parser.parse("omg"));
+    assert_eq!(
+        Err(ParseError::new("lol", "lol", "value matching predicate")),
+        parser.parse("lol")
+    );
+}
+
+#[test]
+fn test_right() {
+    let tag_opener = right(match_literal("<"), identifier);
+
+    assert_eq!(Ok(("/>", "hello".to_string())), tag_opener.parse("<hello/>"));
+    assert_eq!(
+        Err(ParseError::new("foo", "foo", "<")),
+        tag_opener.parse("foo")
+    );
+}
+
+#[test]
+fn test_either() {
+    let parser = either(match_literal("cat"), match_literal("dog"));
+
+    assert_eq!(Ok(("s", ())), parser.parse("cats"));
+    assert_eq!(Ok(("s", ())), parser.parse("dogs"));
+    // both branches fail at position 0, so their `expected` sets are merged
+    assert_eq!(
+        Err(ParseError {
+            remaining: "fish",
+            position: 0,
+            expected: vec!["cat".to_string(), "dog".to_string()],
+        }),
+        parser.parse("fish")
+    );
+}
+
+#[test]
+fn test_either_does_not_use_partial_consumption_from_the_failed_branch() {
+    // "category" starts with "cat", so a backtracking-unsafe either could
+    // be tempted to resume parser2 from wherever "cat" left off ("egory")
+    // instead of the original input - make sure it doesn't
+    let parser = either(map(match_literal("cats"), |_| "cats".to_string()), identifier);
+
+    assert_eq!(Ok(("", "category".to_string())), parser.parse("category"));
+}
+
+#[test]
+fn test_choice() {
+    let parser = choice(vec![
+        BoxedParser::new(match_literal("cat")),
+        BoxedParser::new(match_literal("dog")),
+        BoxedParser::new(match_literal("bird")),
+    ]);
+
+    assert_eq!(Ok(("s", ())), parser.parse("cats"));
+    assert_eq!(Ok(("", ())), parser.parse("bird"));
+    // all three branches fail at position 0, so their `expected` sets are merged
+    assert_eq!(
+        Err(ParseError {
+            remaining: "fish",
+            position: 0,
+            expected: vec!["cat".to_string(), "dog".to_string(), "bird".to_string()],
+        }),
+        parser.parse("fish")
+    );
+}
+
+#[test]
+fn test_and_then() {
+    let parser = match_literal("<").and_then(|_| identifier);
+
+    assert_eq!(Ok(("/>", "hello".to_string())), parser.parse("<hello/>"));
+    assert_eq!(Err(ParseError::new("foo", "foo", "<")), parser.parse("foo"));
+}
+
+#[test]
+fn test_quoted_string() {
+    assert_eq!(
+        Ok(("", String::from("Hello Joe!"))),
+        quoted_string().parse("\"Hello Joe!\"")
+    );
+}
+
+#[test]
+fn test_match_regex() {
+    let parser = match_regex(r"[0-9]+");
+
+    assert_eq!(Ok(("px", String::from("42"))), parser.parse("42px"));
+    assert_eq!(
+        Err(ParseError::new("abc", "abc", r"[0-9]+")),
+        parser.parse("abc")
+    );
+}
+
 // #[test]
 // fn test_pair() {
 //     let tag_opener = pair(match_literal("<"), identifier);
@@ -296,3 +882,94 @@ fn test_identifier() {
 
 //     assert_eq!(Err("!hello/>"), tag_opener("<!hello/>"));
 // }
+
+#[test]
+fn test_single_element() {
+    assert_eq!(
+        Ok((
+            "",
+            Element {
+                name: "div".to_string(),
+                attributes: vec![("class".to_string(), "float".to_string())],
+                children: vec![],
+            }
+        )),
+        single_element().parse("<div class=\"float\"/>")
+    );
+}
+
+#[test]
+fn test_single_element_with_whitespace_before_terminator() {
+    assert_eq!(
+        Ok((
+            "",
+            Element {
+                name: "img".to_string(),
+                attributes: vec![("src".to_string(), "cat.png".to_string())],
+                children: vec![],
+            }
+        )),
+        single_element().parse("<img src=\"cat.png\" />")
+    );
+}
+
+#[test]
+fn test_parent_element_with_children() {
+    let parsed = element("<parent><child/><other-child/></parent>");
+
+    assert_eq!(
+        Ok((
+            "",
+            Element {
+                name: "parent".to_string(),
+                attributes: vec![],
+                children: vec![
+                    Element {
+                        name: "child".to_string(),
+                        attributes: vec![],
+                        children: vec![],
+                    },
+                    Element {
+                        name: "other-child".to_string(),
+                        attributes: vec![],
+                        children: vec![],
+                    },
+                ],
+            }
+        )),
+        parsed
+    );
+}
+
+#[test]
+fn test_parent_element_with_whitespace_before_terminator() {
+    let parsed = element("<parent ><child/></parent>");
+
+    assert_eq!(
+        Ok((
+            "",
+            Element {
+                name: "parent".to_string(),
+                attributes: vec![],
+                children: vec![Element {
+                    name: "child".to_string(),
+                    attributes: vec![],
+                    children: vec![],
+                }],
+            }
+        )),
+        parsed
+    );
+}
+
+#[test]
+fn test_mismatched_closing_tag() {
+    assert_eq!(
+        Err(ParseError::new(
+            "<parent><child/></wrong-name>",
+            "</wrong-name>",
+            "closing tag </parent>"
+        )),
+        element("<parent><child/></wrong-name>")
+    );
+}